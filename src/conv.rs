@@ -1,5 +1,20 @@
 //! Functions for conversions between ticks and natural time units.
 
+/// Compute the greatest common divisor of `a` and `b` using the Euclidean
+/// algorithm. Usable in const context so conversion factors can be reduced
+/// at compile time.
+pub(crate) const fn gcd(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+
+    a
+}
+
 /// Convert microseconds to ticks.
 pub const fn micros_to_ticks(micros: u64, ticks_per_second: u64) -> u64 {
     let (nom, denom) = match ticks_per_second {
@@ -12,10 +27,13 @@ pub const fn micros_to_ticks(micros: u64, ticks_per_second: u64) -> u64 {
         1000000 => (1, 1),
         10000000 => (10, 1),
         100000000 => (100, 1),
-        _ => (ticks_per_second, 1000000),
+        _ => {
+            let g = gcd(ticks_per_second, 1000000);
+            (ticks_per_second / g, 1000000 / g)
+        }
     };
 
-    ((micros as u128) * nom as u128 / denom) as u64
+    ((micros as u128) * nom as u128 / denom as u128) as u64
 }
 
 /// Convert milliseconds to ticks.
@@ -30,10 +48,13 @@ pub const fn millis_to_ticks(millis: u64, ticks_per_second: u64) -> u64 {
         1000000 => (1000, 1),
         10000000 => (10000, 1),
         100000000 => (100000, 1),
-        _ => (ticks_per_second, 1000),
+        _ => {
+            let g = gcd(ticks_per_second, 1000);
+            (ticks_per_second / g, 1000 / g)
+        }
     };
 
-    ((millis as u128) * nom as u128 / denom) as u64
+    ((millis as u128) * nom as u128 / denom as u128) as u64
 }
 
 /// Convert seconds to ticks.
@@ -53,10 +74,13 @@ pub const fn ticks_to_micros(ticks: u64, ticks_per_second: u64) -> u64 {
         1000000 => (1, 1),
         10000000 => (1, 10),
         100000000 => (1, 100),
-        _ => (1000000, ticks_per_second),
+        _ => {
+            let g = gcd(ticks_per_second, 1000000);
+            (1000000 / g, ticks_per_second / g)
+        }
     };
 
-    ((ticks as u128) * nom / denom as u128) as u64
+    ((ticks as u128) * nom as u128 / denom as u128) as u64
 }
 
 /// Convert ticks to milliseconds.
@@ -71,13 +95,83 @@ pub const fn ticks_to_millis(ticks: u64, ticks_per_second: u64) -> u64 {
         1000000 => (1, 1000),
         10000000 => (1, 10000),
         100000000 => (1, 100000),
-        _ => (1000, ticks_per_second),
+        _ => {
+            let g = gcd(ticks_per_second, 1000);
+            (1000 / g, ticks_per_second / g)
+        }
     };
 
-    ((ticks as u128) * nom / denom as u128) as u64
+    ((ticks as u128) * nom as u128 / denom as u128) as u64
 }
 
 /// Convert ticks to seconds.
 pub const fn ticks_to_secs(ticks: u64, ticks_per_second: u64) -> u64 {
     ticks / ticks_per_second
 }
+
+/// Convert nanoseconds to ticks.
+///
+/// The multiply is carried out with a `u128` intermediate, so it cannot
+/// overflow; the result is rounded toward zero. At rates up to 1 GHz every
+/// `u64` nanosecond count fits; above that, panics if the tick count does
+/// not fit in a `u64`.
+pub const fn nanos_to_ticks(nanos: u64, ticks_per_second: u64) -> u64 {
+    let g = gcd(ticks_per_second, 1000000000);
+    let nom = ticks_per_second / g;
+    let denom = 1000000000 / g;
+
+    let ticks = (nanos as u128) * nom as u128 / denom as u128;
+    assert!(ticks <= u64::MAX as u128, "Overflow when converting nanoseconds to ticks.");
+    ticks as u64
+}
+
+/// Convert ticks to nanoseconds.
+///
+/// The multiply is carried out with a `u128` intermediate, so it cannot
+/// overflow; the result is rounded toward zero. At rates of 1 GHz and above
+/// every `u64` tick count fits; below that, panics if the nanosecond count
+/// does not fit in a `u64` (about 584 years).
+pub const fn ticks_to_nanos(ticks: u64, ticks_per_second: u64) -> u64 {
+    let g = gcd(ticks_per_second, 1000000000);
+    let nom = 1000000000 / g;
+    let denom = ticks_per_second / g;
+
+    let nanos = (ticks as u128) * nom as u128 / denom as u128;
+    assert!(nanos <= u64::MAX as u128, "Overflow when converting ticks to nanoseconds.");
+    nanos as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 32768 Hz is the common watch crystal rate: not a power of ten, so it
+    // exercises the GCD-reduced fallback arm rather than the fast paths.
+    const WATCH_CRYSTAL_HZ: u64 = 32768;
+
+    #[test]
+    fn micros_round_trip_at_watch_crystal_rate() {
+        assert_eq!(micros_to_ticks(15625, WATCH_CRYSTAL_HZ), 512);
+        assert_eq!(ticks_to_micros(512, WATCH_CRYSTAL_HZ), 15625);
+        assert_eq!(micros_to_ticks(1000000, WATCH_CRYSTAL_HZ), WATCH_CRYSTAL_HZ);
+    }
+
+    #[test]
+    fn millis_round_trip_at_watch_crystal_rate() {
+        assert_eq!(millis_to_ticks(125, WATCH_CRYSTAL_HZ), 4096);
+        assert_eq!(ticks_to_millis(4096, WATCH_CRYSTAL_HZ), 125);
+    }
+
+    #[test]
+    fn nanos_round_trip_at_watch_crystal_rate() {
+        assert_eq!(nanos_to_ticks(1953125, WATCH_CRYSTAL_HZ), 64);
+        assert_eq!(ticks_to_nanos(64, WATCH_CRYSTAL_HZ), 1953125);
+    }
+
+    #[test]
+    fn nanos_round_trip_at_gigahertz_rate() {
+        const CORE_CLOCK_HZ: u64 = 1_000_000_000;
+        assert_eq!(nanos_to_ticks(1, CORE_CLOCK_HZ), 1);
+        assert_eq!(ticks_to_nanos(1, CORE_CLOCK_HZ), 1);
+    }
+}
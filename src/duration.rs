@@ -61,8 +61,17 @@ impl<C> Duration<C>
 where
     C: ClockTick,
 {
+    /// The zero duration.
+    pub const ZERO: Self = Self::from_ticks(0);
+
+    /// The smallest representable duration.
+    pub const MIN: Self = Self::from_ticks(u64::MIN);
+
+    /// The largest representable duration.
+    pub const MAX: Self = Self::from_ticks(u64::MAX);
+
     /// Create a duration from a ticks count.
-    pub fn from_ticks(ticks: u64) -> Self {
+    pub const fn from_ticks(ticks: u64) -> Self {
         Self {
             ticks,
             clock: PhantomData,
@@ -70,47 +79,76 @@ where
     }
 
     /// Create a duration from a microseconds count. Panics on overflow.
-    pub fn from_micros(micros: u64) -> Self {
+    pub const fn from_micros(micros: u64) -> Self {
         Self {
-            ticks: conv::micros_to_ticks(micros, C::ticks_per_second()),
+            ticks: conv::micros_to_ticks(micros, C::TICKS_PER_SECOND),
             clock: PhantomData,
         }
     }
 
     /// Create a duration from a milliseconds count. Panics on overflow.
-    pub fn from_millis(millis: u64) -> Self {
+    pub const fn from_millis(millis: u64) -> Self {
         Self {
-            ticks: conv::millis_to_ticks(millis, C::ticks_per_second()),
+            ticks: conv::millis_to_ticks(millis, C::TICKS_PER_SECOND),
             clock: PhantomData,
         }
     }
 
     /// Create a duration from a seconds count. Panics on overflow.
-    pub fn from_secs(secs: u64) -> Self {
+    pub const fn from_secs(secs: u64) -> Self {
         Self {
-            ticks: conv::secs_to_ticks(secs, C::ticks_per_second()),
+            ticks: conv::secs_to_ticks(secs, C::TICKS_PER_SECOND),
+            clock: PhantomData,
+        }
+    }
+
+    /// Create a duration from a nanoseconds count. Panics on overflow.
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self {
+            ticks: conv::nanos_to_ticks(nanos, C::TICKS_PER_SECOND),
             clock: PhantomData,
         }
     }
 
     /// Return tick count.
-    pub fn to_ticks(&self) -> u64 {
+    pub const fn to_ticks(&self) -> u64 {
         self.ticks
     }
 
     /// Return duration as microseconds. Panics on overflow.
-    pub fn to_micros(&self) -> u64 {
-        conv::ticks_to_micros(self.ticks, C::ticks_per_second())
+    pub const fn to_micros(&self) -> u64 {
+        conv::ticks_to_micros(self.ticks, C::TICKS_PER_SECOND)
     }
 
     /// Return duration as milliseconds. Panics on overflow.
-    pub fn to_millis(&self) -> u64 {
-        conv::ticks_to_millis(self.ticks, C::ticks_per_second())
+    pub const fn to_millis(&self) -> u64 {
+        conv::ticks_to_millis(self.ticks, C::TICKS_PER_SECOND)
     }
 
     /// Return duration as seconds.
-    pub fn to_secs(&self) -> u64 {
-        conv::ticks_to_secs(self.ticks, C::ticks_per_second())
+    pub const fn to_secs(&self) -> u64 {
+        conv::ticks_to_secs(self.ticks, C::TICKS_PER_SECOND)
+    }
+
+    /// Return duration as nanoseconds. Panics on overflow.
+    pub const fn to_nanos(&self) -> u64 {
+        conv::ticks_to_nanos(self.ticks, C::TICKS_PER_SECOND)
+    }
+
+    /// Rescale this duration to a different clock, preserving the time span
+    /// as closely as the target clock's resolution allows.
+    pub const fn to_clock<D>(self) -> Duration<D>
+    where
+        D: ClockTick,
+    {
+        let g = conv::gcd(C::TICKS_PER_SECOND, D::TICKS_PER_SECOND);
+        let nom = D::TICKS_PER_SECOND / g;
+        let denom = C::TICKS_PER_SECOND / g;
+
+        Duration {
+            ticks: ((self.ticks as u128) * nom as u128 / denom as u128) as u64,
+            clock: PhantomData,
+        }
     }
 
     /// Add durations, return a new duration or None in case of overflow.
@@ -129,6 +167,22 @@ where
         })
     }
 
+    /// Add durations, saturating at `Duration::MAX` in case of overflow.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            ticks: self.ticks.saturating_add(rhs.ticks),
+            clock: PhantomData,
+        }
+    }
+
+    /// Subtract durations, saturating at `Duration::ZERO` in case of overflow.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            ticks: self.ticks.saturating_sub(rhs.ticks),
+            clock: PhantomData,
+        }
+    }
+
     /// Multiply durations by a scalar, return a new duration or None in case of overflow.
     pub fn checked_mul(self, rhs: u32) -> Option<Self> {
         self.ticks.checked_mul(rhs as _).map(|ticks| Self {
@@ -249,3 +303,49 @@ where
         write!(f, "{} ticks", self.ticks)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_clocks::{OneMegahertzTimer, WatchCrystal};
+
+    #[test]
+    fn to_clock_rescales_whole_second_exactly() {
+        let rescaled = Duration::<WatchCrystal>::from_secs(1).to_clock::<OneMegahertzTimer>();
+        assert_eq!(
+            rescaled.to_ticks(),
+            Duration::<OneMegahertzTimer>::from_secs(1).to_ticks()
+        );
+    }
+
+    #[test]
+    fn to_clock_rescales_sub_second_span_exactly() {
+        let rescaled = Duration::<WatchCrystal>::from_ticks(512).to_clock::<OneMegahertzTimer>();
+        assert_eq!(rescaled.to_ticks(), 15625);
+    }
+
+    #[test]
+    fn consts_hold_expected_values() {
+        assert_eq!(Duration::<WatchCrystal>::ZERO.to_ticks(), 0);
+        assert_eq!(Duration::<WatchCrystal>::MIN.to_ticks(), u64::MIN);
+        assert_eq!(Duration::<WatchCrystal>::MAX.to_ticks(), u64::MAX);
+    }
+
+    #[test]
+    fn saturating_add_saturates_at_max() {
+        let duration = Duration::<WatchCrystal>::from_ticks(u64::MAX - 1);
+        assert_eq!(
+            duration.saturating_add(Duration::from_ticks(10)),
+            Duration::MAX
+        );
+    }
+
+    #[test]
+    fn saturating_sub_saturates_at_zero() {
+        let duration = Duration::<WatchCrystal>::from_ticks(1);
+        assert_eq!(
+            duration.saturating_sub(Duration::from_ticks(10)),
+            Duration::ZERO
+        );
+    }
+}
@@ -61,6 +61,12 @@ impl<C> Instant<C>
 where
     C: ClockTick,
 {
+    /// The earliest representable instant.
+    pub const MIN: Self = Self::from_ticks(u64::MIN);
+
+    /// The latest representable instant.
+    pub const MAX: Self = Self::from_ticks(u64::MAX);
+
     /// Return instant with current time.
     pub fn now() -> Self {
         Self {
@@ -101,6 +107,14 @@ where
         }
     }
 
+    /// Create an instant from a nanoseconds count since boot.
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self {
+            ticks: conv::nanos_to_ticks(nanos, C::TICKS_PER_SECOND),
+            clock: PhantomData,
+        }
+    }
+
     /// Return ticks count since boot.
     pub const fn to_ticks(&self) -> u64 {
         self.ticks
@@ -121,13 +135,31 @@ where
         conv::ticks_to_secs(self.ticks, C::TICKS_PER_SECOND)
     }
 
+    /// Return nanoseconds count since boot. Panics on overflow.
+    pub const fn to_nanos(&self) -> u64 {
+        conv::ticks_to_nanos(self.ticks, C::TICKS_PER_SECOND)
+    }
+
+    /// Rescale this instant to a different clock, preserving the point in
+    /// time as closely as the target clock's resolution allows.
+    pub const fn to_clock<D>(self) -> Instant<D>
+    where
+        D: ClockTick,
+    {
+        let g = conv::gcd(C::TICKS_PER_SECOND, D::TICKS_PER_SECOND);
+        let nom = D::TICKS_PER_SECOND / g;
+        let denom = C::TICKS_PER_SECOND / g;
+
+        Instant {
+            ticks: ((self.ticks as u128) * nom as u128 / denom as u128) as u64,
+            clock: PhantomData,
+        }
+    }
+
     /// Return duration between current instant and an earlier one.
     /// Panics on overflow.
     pub fn duration_since(&self, earlier: Self) -> Duration<C> {
-        Duration {
-            ticks: self.ticks.checked_sub(earlier.ticks).unwrap(),
-            clock: PhantomData,
-        }
+        self.checked_duration_since(earlier).unwrap()
     }
 
     /// Duration elapsed since this instant.
@@ -135,6 +167,22 @@ where
         Self::now() - *self
     }
 
+    /// Return duration between current instant and an earlier one, or None
+    /// if `earlier` is actually later than `self`.
+    pub fn checked_duration_since(&self, earlier: Self) -> Option<Duration<C>> {
+        self.ticks.checked_sub(earlier.ticks).map(|ticks| Duration {
+            ticks,
+            clock: PhantomData,
+        })
+    }
+
+    /// Return duration between current instant and an earlier one, or
+    /// `Duration::ZERO` if `earlier` is actually later than `self`.
+    pub fn saturating_duration_since(&self, earlier: Self) -> Duration<C> {
+        self.checked_duration_since(earlier)
+            .unwrap_or(Duration::ZERO)
+    }
+
     /// Adds one duration to self, returning a new `Instant` or None in the event of an overflow.
     pub fn checked_add(&self, duration: Duration<C>) -> Option<Self> {
         self.ticks.checked_add(duration.ticks).map(|ticks| Self {
@@ -150,6 +198,22 @@ where
             clock: PhantomData,
         })
     }
+
+    /// Adds one duration to self, saturating at `Instant::MAX` in the event of an overflow.
+    pub fn saturating_add(&self, duration: Duration<C>) -> Self {
+        Self {
+            ticks: self.ticks.saturating_add(duration.ticks),
+            clock: PhantomData,
+        }
+    }
+
+    /// Subtracts one duration from self, saturating at `Instant::MIN` in the event of an overflow.
+    pub fn saturating_sub(&self, duration: Duration<C>) -> Self {
+        Self {
+            ticks: self.ticks.saturating_sub(duration.ticks),
+            clock: PhantomData,
+        }
+    }
 }
 
 impl<C> Add<Duration<C>> for Instant<C>
@@ -213,3 +277,62 @@ where
         write!(f, "{} ticks", self.ticks)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_clocks::{OneMegahertzTimer, WatchCrystal};
+
+    #[test]
+    fn to_clock_rescales_whole_second_exactly() {
+        let rescaled = Instant::<WatchCrystal>::from_secs(1).to_clock::<OneMegahertzTimer>();
+        assert_eq!(
+            rescaled.to_ticks(),
+            Instant::<OneMegahertzTimer>::from_secs(1).to_ticks()
+        );
+    }
+
+    #[test]
+    fn to_clock_rescales_sub_second_span_exactly() {
+        let rescaled = Instant::<WatchCrystal>::from_ticks(512).to_clock::<OneMegahertzTimer>();
+        assert_eq!(rescaled.to_ticks(), 15625);
+    }
+
+    #[test]
+    fn consts_hold_expected_values() {
+        assert_eq!(Instant::<WatchCrystal>::MIN.to_ticks(), u64::MIN);
+        assert_eq!(Instant::<WatchCrystal>::MAX.to_ticks(), u64::MAX);
+    }
+
+    #[test]
+    fn checked_duration_since_returns_none_when_earlier_is_later() {
+        let earlier = Instant::<WatchCrystal>::from_ticks(10);
+        let later = Instant::<WatchCrystal>::from_ticks(5);
+        assert_eq!(later.checked_duration_since(earlier), None);
+    }
+
+    #[test]
+    fn saturating_duration_since_returns_zero_when_earlier_is_later() {
+        let earlier = Instant::<WatchCrystal>::from_ticks(10);
+        let later = Instant::<WatchCrystal>::from_ticks(5);
+        assert_eq!(later.saturating_duration_since(earlier), Duration::ZERO);
+    }
+
+    #[test]
+    fn saturating_add_saturates_at_max() {
+        let instant = Instant::<WatchCrystal>::from_ticks(u64::MAX - 1);
+        assert_eq!(
+            instant.saturating_add(Duration::from_ticks(10)),
+            Instant::MAX
+        );
+    }
+
+    #[test]
+    fn saturating_sub_saturates_at_min() {
+        let instant = Instant::<WatchCrystal>::from_ticks(1);
+        assert_eq!(
+            instant.saturating_sub(Duration::from_ticks(10)),
+            Instant::MIN
+        );
+    }
+}
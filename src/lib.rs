@@ -1,19 +1,57 @@
 #![doc = include_str!("../README.md")]
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
 pub mod conv;
 pub mod delay;
 pub mod duration;
 pub mod instant;
+pub mod timer;
 
 pub use duration::Duration;
 pub use instant::Instant;
+pub use timer::Timer;
 
 /// Trait to be implemented by clock sources.
 pub trait ClockTick {
+    /// Number of ticks per second.
+    const TICKS_PER_SECOND: u64;
+
     /// Return elapsed ticks since start.
     fn ticks() -> u64;
 
     /// Return the number of ticks per second.
-    fn ticks_per_second() -> u64;
+    fn ticks_per_second() -> u64 {
+        Self::TICKS_PER_SECOND
+    }
+}
+
+/// Clock fixtures shared by unit tests across the crate.
+#[cfg(test)]
+pub(crate) mod test_clocks {
+    use crate::ClockTick;
+
+    /// A watch crystal and a 1 MHz timer: two real-world rates whose GCD
+    /// reduction isn't a no-op, so a rescale that silently dropped precision
+    /// would show up as a mismatched tick count.
+    #[derive(Debug)]
+    pub(crate) struct WatchCrystal;
+
+    impl ClockTick for WatchCrystal {
+        const TICKS_PER_SECOND: u64 = 32768;
+
+        fn ticks() -> u64 {
+            0
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct OneMegahertzTimer;
+
+    impl ClockTick for OneMegahertzTimer {
+        const TICKS_PER_SECOND: u64 = 1_000_000;
+
+        fn ticks() -> u64 {
+            0
+        }
+    }
 }
@@ -0,0 +1,328 @@
+//! Async timer futures backed by a wake-scheduling extension to `ClockTick`.
+//!
+//! This gives `no_std` users `Timer::after`/`Timer::at` `.await` ergonomics
+//! without pulling in a full executor: a hardware timer ISR drives a
+//! [`TimerQueue`], which wakes every pending [`Timer`] whose deadline has
+//! passed.
+
+use core::cell::UnsafeCell;
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use crate::{ClockTick, Duration, Instant};
+
+/// Extension trait for clock sources that can schedule a wakeup at a given
+/// tick count, backing the [`Timer`] future.
+pub trait AlarmClock: ClockTick {
+    /// Arrange for `waker` to be woken once the clock reaches `at_ticks`.
+    fn schedule_wake(at_ticks: u64, waker: &Waker);
+
+    /// Run `f` with interrupts masked, giving mutually exclusive access
+    /// between task context (e.g. [`TimerQueue::schedule`]) and the timer
+    /// ISR (e.g. [`TimerQueue::wake_expired`]) on the same core.
+    ///
+    /// A plain spinlock is not enough here: if the ISR fires while task
+    /// context holds it, the ISR's spin loop never yields back to the code
+    /// it interrupted, deadlocking the core.
+    fn critical_section<R>(f: impl FnOnce() -> R) -> R;
+}
+
+/// Future that completes once `Instant::<C>::now()` reaches a deadline.
+pub struct Timer<C: AlarmClock> {
+    deadline: Instant<C>,
+}
+
+impl<C: AlarmClock> Timer<C> {
+    /// Create a timer that fires `duration` from now.
+    pub fn after(duration: Duration<C>) -> Self {
+        Self::at(Instant::now() + duration)
+    }
+
+    /// Create a timer that fires at `instant`.
+    pub fn at(instant: Instant<C>) -> Self {
+        Self { deadline: instant }
+    }
+}
+
+impl<C: AlarmClock> Future for Timer<C> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if Instant::<C>::now() >= self.deadline {
+            Poll::Ready(())
+        } else {
+            C::schedule_wake(self.deadline.to_ticks(), cx.waker());
+            Poll::Pending
+        }
+    }
+}
+
+/// Fixed-capacity, allocation-free timer queue that a hardware timer ISR can
+/// drain to implement [`AlarmClock::schedule_wake`].
+///
+/// `N` bounds the number of timers that can be pending at once;
+/// [`schedule`](Self::schedule) silently drops the wakeup if the queue is
+/// already full rather than panicking in interrupt context. A future polled
+/// repeatedly before its deadline (as `select!`/`join!` combinators commonly
+/// do) updates its existing slot instead of consuming a fresh one each time.
+/// Slots are keyed by waker, so timers polled from the same task share one
+/// slot, which keeps the earliest of their deadlines.
+pub struct TimerQueue<C: AlarmClock, const N: usize> {
+    slots: UnsafeCell<[Option<(u64, Waker)>; N]>,
+    clock: PhantomData<C>,
+}
+
+unsafe impl<C: AlarmClock, const N: usize> Sync for TimerQueue<C, N> {}
+
+impl<C: AlarmClock, const N: usize> TimerQueue<C, N> {
+    /// Create an empty timer queue.
+    pub const fn new() -> Self {
+        Self {
+            slots: UnsafeCell::new([const { None }; N]),
+            clock: PhantomData,
+        }
+    }
+
+    /// Register a wakeup for `waker` at `at_ticks`, updating the existing
+    /// entry for `waker` if one is already pending; that entry keeps the
+    /// earlier of the two deadlines.
+    ///
+    /// Intended to be called from [`AlarmClock::schedule_wake`].
+    pub fn schedule(&self, at_ticks: u64, waker: &Waker) {
+        C::critical_section(|| {
+            let slots = unsafe { &mut *self.slots.get() };
+
+            let index = slots
+                .iter()
+                .position(|slot| matches!(slot, Some((_, w)) if w.will_wake(waker)))
+                .or_else(|| slots.iter().position(|slot| slot.is_none()));
+
+            if let Some(index) = index {
+                let at_ticks = match &slots[index] {
+                    Some((deadline, _)) => at_ticks.min(*deadline),
+                    None => at_ticks,
+                };
+                slots[index] = Some((at_ticks, waker.clone()));
+            }
+        });
+    }
+
+    /// Wake and remove every entry whose deadline is at or before `now`.
+    ///
+    /// Call this from the hardware timer ISR each time it fires. Slots are
+    /// scanned in no particular order, so there's nothing to gain from
+    /// keeping them sorted in [`schedule`](Self::schedule) — doing so would
+    /// only hold the critical section open longer.
+    pub fn wake_expired(&self, now: u64) {
+        let mut expired: [Option<Waker>; N] = core::array::from_fn(|_| None);
+        let mut count = 0;
+
+        C::critical_section(|| {
+            let slots = unsafe { &mut *self.slots.get() };
+
+            for slot in slots.iter_mut() {
+                let is_expired = matches!(slot, Some((deadline, _)) if *deadline <= now);
+
+                if is_expired {
+                    if let Some((_, waker)) = slot.take() {
+                        expired[count] = Some(waker);
+                        count += 1;
+                    }
+                }
+            }
+        });
+
+        // Wake outside the critical section: a synchronous re-poll of the
+        // task (the common no_std executor-less pattern) would otherwise
+        // call back into `schedule` and deadlock re-entering the section.
+        for waker in expired.into_iter().flatten() {
+            waker.wake();
+        }
+    }
+}
+
+impl<C: AlarmClock, const N: usize> Default for TimerQueue<C, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use std::cell::Cell;
+    use std::task::{RawWaker, RawWakerVTable};
+    use std::thread_local;
+
+    // `core::task::Waker` has no safe constructor in `no_std` without an
+    // executor crate, so tests build one by hand: the data pointer is an
+    // `&AtomicBool` that `wake`/`wake_by_ref` flip to `true`.
+    fn test_waker(woken: &AtomicBool) -> Waker {
+        fn clone(ptr: *const ()) -> RawWaker {
+            RawWaker::new(ptr, &VTABLE)
+        }
+        fn wake(ptr: *const ()) {
+            wake_by_ref(ptr);
+        }
+        fn wake_by_ref(ptr: *const ()) {
+            unsafe { &*(ptr as *const AtomicBool) }.store(true, Ordering::SeqCst);
+        }
+        fn drop(_ptr: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop);
+        let raw = RawWaker::new(woken as *const AtomicBool as *const (), &VTABLE);
+        unsafe { Waker::from_raw(raw) }
+    }
+
+    // A clock under test control: `NOW` stands in for the free-running
+    // hardware tick count, and `QUEUE` stands in for the single static
+    // `TimerQueue` a real `AlarmClock` impl would drain from its ISR.
+    // `thread_local!` keeps each test's state isolated, since the default
+    // test harness runs every test on its own thread.
+    struct TestClock;
+
+    thread_local! {
+        static NOW: Cell<u64> = const { Cell::new(0) };
+        static QUEUE: TimerQueue<TestClock, 2> = const { TimerQueue::new() };
+    }
+
+    fn set_now(ticks: u64) {
+        NOW.with(|now| now.set(ticks));
+    }
+
+    impl ClockTick for TestClock {
+        const TICKS_PER_SECOND: u64 = 1_000_000;
+
+        fn ticks() -> u64 {
+            NOW.with(|now| now.get())
+        }
+    }
+
+    impl AlarmClock for TestClock {
+        fn schedule_wake(at_ticks: u64, waker: &Waker) {
+            QUEUE.with(|queue| queue.schedule(at_ticks, waker));
+        }
+
+        fn critical_section<R>(f: impl FnOnce() -> R) -> R {
+            f()
+        }
+    }
+
+    #[test]
+    fn poll_before_deadline_returns_pending_and_registers_waker() {
+        set_now(0);
+        let woken = AtomicBool::new(false);
+        let waker = test_waker(&woken);
+        let mut cx = Context::from_waker(&waker);
+        let mut timer = Timer::<TestClock>::at(Instant::from_ticks(10));
+
+        assert_eq!(Pin::new(&mut timer).poll(&mut cx), Poll::Pending);
+
+        // The deadline is now in the past: if `poll` registered the waker,
+        // draining the queue wakes it.
+        QUEUE.with(|queue| queue.wake_expired(10));
+        assert!(woken.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn poll_at_or_after_deadline_returns_ready_without_touching_queue() {
+        set_now(10);
+        let woken = AtomicBool::new(false);
+        let waker = test_waker(&woken);
+        let mut cx = Context::from_waker(&waker);
+        let mut timer = Timer::<TestClock>::at(Instant::from_ticks(10));
+
+        assert_eq!(Pin::new(&mut timer).poll(&mut cx), Poll::Ready(()));
+
+        // Nothing was registered, so draining the (empty) queue can't wake it.
+        QUEUE.with(|queue| queue.wake_expired(10));
+        assert!(!woken.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn repolling_updates_existing_slot_instead_of_consuming_a_new_one() {
+        set_now(0);
+        let woken = AtomicBool::new(false);
+        let other_woken = AtomicBool::new(false);
+        let waker = test_waker(&woken);
+        let other_waker = test_waker(&other_woken);
+        let mut cx = Context::from_waker(&waker);
+        let mut other_cx = Context::from_waker(&other_waker);
+        let mut first = Timer::<TestClock>::at(Instant::from_ticks(10));
+        let mut second = Timer::<TestClock>::at(Instant::from_ticks(20));
+
+        // Re-poll the same timer (same waker) enough times that it would
+        // overflow the 2-slot queue if each poll consumed a fresh slot.
+        for _ in 0..3 {
+            assert_eq!(Pin::new(&mut first).poll(&mut cx), Poll::Pending);
+        }
+        // A timer with a different waker still gets the second slot, proving
+        // `first` only ever occupies one: otherwise its wakeup is dropped.
+        assert_eq!(Pin::new(&mut second).poll(&mut other_cx), Poll::Pending);
+
+        QUEUE.with(|queue| queue.wake_expired(20));
+        assert!(woken.load(Ordering::SeqCst));
+        assert!(other_woken.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn wake_expired_only_wakes_entries_at_or_before_now() {
+        set_now(0);
+        let woken_early = AtomicBool::new(false);
+        let woken_late = AtomicBool::new(false);
+        let waker_early = test_waker(&woken_early);
+        let waker_late = test_waker(&woken_late);
+        let mut cx_early = Context::from_waker(&waker_early);
+        let mut cx_late = Context::from_waker(&waker_late);
+        let mut early = Timer::<TestClock>::at(Instant::from_ticks(10));
+        let mut late = Timer::<TestClock>::at(Instant::from_ticks(20));
+
+        assert_eq!(Pin::new(&mut early).poll(&mut cx_early), Poll::Pending);
+        assert_eq!(Pin::new(&mut late).poll(&mut cx_late), Poll::Pending);
+
+        QUEUE.with(|queue| queue.wake_expired(15));
+
+        assert!(woken_early.load(Ordering::SeqCst));
+        assert!(!woken_late.load(Ordering::SeqCst));
+
+        QUEUE.with(|queue| queue.wake_expired(20));
+        assert!(woken_late.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn registrations_past_capacity_are_silently_dropped() {
+        set_now(0);
+        let woken = [
+            AtomicBool::new(false),
+            AtomicBool::new(false),
+            AtomicBool::new(false),
+        ];
+        let wakers = [
+            test_waker(&woken[0]),
+            test_waker(&woken[1]),
+            test_waker(&woken[2]),
+        ];
+        // The queue under test has capacity 2: three distinct, never-ready
+        // timers compete for two slots, so the third registration is dropped.
+        let mut timers = [
+            Timer::<TestClock>::at(Instant::from_ticks(10)),
+            Timer::<TestClock>::at(Instant::from_ticks(20)),
+            Timer::<TestClock>::at(Instant::from_ticks(30)),
+        ];
+
+        for (timer, waker) in timers.iter_mut().zip(wakers.iter()) {
+            let mut cx = Context::from_waker(waker);
+            assert_eq!(Pin::new(timer).poll(&mut cx), Poll::Pending);
+        }
+
+        QUEUE.with(|queue| queue.wake_expired(30));
+
+        assert!(woken[0].load(Ordering::SeqCst));
+        assert!(woken[1].load(Ordering::SeqCst));
+        // Silently dropped: no panic above, and nothing wakes it.
+        assert!(!woken[2].load(Ordering::SeqCst));
+    }
+}